@@ -0,0 +1,89 @@
+use anyhow::Result;
+
+use crate::models::ShowGroup;
+use crate::provider::{MusicProvider, PlaylistRef};
+
+/// Drives a `MusicProvider` without main's CLI/orchestration concerns mixed
+/// in, so publishing is unit-testable against a mock implementation instead
+/// of only through a live Spotify/YouTube account.
+///
+/// This stops at `&ShowGroup` rather than resolving it to a normalized,
+/// provider-agnostic model first: `MusicProvider::search_track` is one
+/// track at a time over `&mut self`, but `SpotifyClient`'s real resolution
+/// (`resolve_track_uris`) needs to search a show's tracks concurrently and
+/// share one token/cache across the batch (see provider.rs's `SpotifyClient`
+/// impl), so `Engine` can't drive that path through the trait without
+/// serializing it. `YouTubeProvider` resolves one track at a time already,
+/// and its episode/track bucketing does go through the shared `Playable`
+/// model (see provider.rs).
+pub struct Engine<P: MusicProvider> {
+    pub provider: P,
+}
+
+impl<P: MusicProvider> Engine<P> {
+    pub fn new(provider: P) -> Self {
+        Self { provider }
+    }
+
+    pub async fn publish(&mut self, show_group: &ShowGroup) -> Result<PlaylistRef> {
+        self.provider.create_or_update_playlist(show_group).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Show, ShowEpisode, Track};
+    use crate::playable::{Playable, ProviderId};
+
+    struct MockProvider {
+        published: Vec<String>,
+    }
+
+    impl MusicProvider for MockProvider {
+        async fn search_track(&mut self, track: &Track) -> Result<Option<Playable<'static>>> {
+            Ok(Some(Playable::Track(ProviderId::new(format!(
+                "mock:{}",
+                track.song
+            )))))
+        }
+
+        async fn create_or_update_playlist(&mut self, show_group: &ShowGroup) -> Result<PlaylistRef> {
+            self.published.push(show_group.playlist_name());
+            Ok(PlaylistRef {
+                name: show_group.playlist_name(),
+                external_url: None,
+            })
+        }
+    }
+
+    fn show_group() -> ShowGroup {
+        ShowGroup {
+            station: "KALX".to_string(),
+            show_name: "Test Show".to_string(),
+            episodes: vec![ShowEpisode {
+                show: Show {
+                    id: 1,
+                    title: "Test Show".to_string(),
+                    url: "https://example.com".to_string(),
+                    start_time: String::new(),
+                    end_time: String::new(),
+                },
+                tracks: Vec::new(),
+            }],
+        }
+    }
+
+    #[tokio::test]
+    async fn publish_forwards_to_the_provider() {
+        let mut engine = Engine::new(MockProvider {
+            published: Vec::new(),
+        });
+        let group = show_group();
+
+        let playlist = engine.publish(&group).await.unwrap();
+
+        assert_eq!(playlist.name, group.playlist_name());
+        assert_eq!(engine.provider.published, vec![group.playlist_name()]);
+    }
+}
@@ -4,14 +4,53 @@ use clap::Parser;
 use std::path::PathBuf;
 
 mod config;
+mod engine;
 mod models;
+mod playable;
+mod provider;
+mod resolver;
 mod scraper;
 mod spotify;
+mod telemetry;
 
 use config::AppConfig;
-use models::{ShowEpisode, ShowGroup};
+use engine::Engine;
+use models::{ShowEpisode, ShowGroup, Track};
+use playable::Playable;
+use provider::{MusicProvider, PlaylistRef, YouTubeProvider};
 use spotify::SpotifyClient;
 use std::collections::HashMap;
+use telemetry::TelemetrySink;
+
+#[derive(Clone, Copy, Debug, clap::ValueEnum)]
+enum ProviderKind {
+    Spotify,
+    Youtube,
+}
+
+/// Whichever backend is actually publishing playlists this run, wrapped so
+/// `Engine` can drive it through `MusicProvider` without knowing which one
+/// a given invocation picked.
+enum ActiveProvider {
+    Spotify(SpotifyClient),
+    Youtube(YouTubeProvider),
+}
+
+impl MusicProvider for ActiveProvider {
+    async fn search_track(&mut self, track: &Track) -> Result<Option<Playable<'static>>> {
+        match self {
+            ActiveProvider::Spotify(client) => client.search_track(track).await,
+            ActiveProvider::Youtube(client) => client.search_track(track).await,
+        }
+    }
+
+    async fn create_or_update_playlist(&mut self, show_group: &ShowGroup) -> Result<PlaylistRef> {
+        match self {
+            ActiveProvider::Spotify(client) => client.create_or_update_playlist(show_group).await,
+            ActiveProvider::Youtube(client) => client.create_or_update_playlist(show_group).await,
+        }
+    }
+}
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
@@ -24,10 +63,14 @@ struct Args {
     #[arg(short, long)]
     date: Option<String>,
 
-    /// Create Spotify playlists from scraped data
+    /// Create playlists from scraped data (destination picked by --provider)
     #[arg(short = 's', long)]
     spotify: bool,
 
+    /// Which provider to publish to when --spotify is set
+    #[arg(long, value_enum, default_value = "spotify")]
+    provider: ProviderKind,
+
     /// Output markdown list of all cached playlists
     #[arg(long)]
     list_playlists: bool,
@@ -35,6 +78,8 @@ struct Args {
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    let _sentry_guard = telemetry::init_sentry();
+
     let args = Args::parse();
 
     // Handle list playlists command first
@@ -67,17 +112,26 @@ async fn main() -> Result<()> {
         start_date, end_date
     );
 
-    // Initialize Spotify client if needed
-    let mut spotify_client = if args.spotify {
-        println!("Initializing Spotify client...");
-        let client = SpotifyClient::new().await?;
-        Some(client)
+    // Initialize the selected provider if needed
+    let mut engine = if args.spotify {
+        println!("Initializing {:?} client...", args.provider);
+        let active = match args.provider {
+            ProviderKind::Spotify => ActiveProvider::Spotify(SpotifyClient::new().await?),
+            ProviderKind::Youtube => {
+                let instance_url = std::env::var("INVIDIOUS_INSTANCE_URL").map_err(|_| {
+                    anyhow::anyhow!("--provider youtube requires the INVIDIOUS_INSTANCE_URL environment variable")
+                })?;
+                ActiveProvider::Youtube(YouTubeProvider::new(instance_url)?)
+            }
+        };
+        Some(Engine::new(active))
     } else {
         None
     };
 
     // Collect all episodes across the 7-day period
     let mut all_episodes: HashMap<String, Vec<ShowEpisode>> = HashMap::new();
+    let telemetry = telemetry::default_sink();
 
     // Process each station
     for (station_name, station_config) in &config.stations {
@@ -87,6 +141,9 @@ async fn main() -> Result<()> {
         let mut current_date = start_date;
         while current_date <= end_date {
             println!("  Fetching shows for {}", current_date);
+            let date_string = current_date.to_string();
+            let station_tags: [(&str, &str); 2] =
+                [("station", station_name.as_str()), ("date", &date_string)];
 
             match scraper::fetch_shows_for_date(station_name, current_date).await {
                 Ok(shows) => {
@@ -104,8 +161,18 @@ async fn main() -> Result<()> {
                     for show in shows_to_process {
                         println!("    Processing: {}", show.title);
 
+                        let show_id_string = show.id.to_string();
+                        let show_tags: [(&str, &str); 4] = [
+                            ("station", station_name.as_str()),
+                            ("date", &date_string),
+                            ("show", show.title.as_str()),
+                            ("spinitron_show_id", &show_id_string),
+                        ];
+
                         // Fetch and parse playlist
-                        match scraper::fetch_playlist(&show.url).await {
+                        match scraper::fetch_playlist(&show.url, telemetry.as_ref(), &show_tags)
+                            .await
+                        {
                             Ok(tracks) => {
                                 let episode = ShowEpisode {
                                     show: show.clone(),
@@ -124,12 +191,14 @@ async fn main() -> Result<()> {
                                     "    ❌ Failed to fetch playlist for {}: {}",
                                     show.title, e
                                 );
+                                telemetry.capture_error("fetch_playlist", &show_tags, &e);
                             }
                         }
                     }
                 }
                 Err(e) => {
                     eprintln!("  ❌ Failed to fetch shows for {}: {}", current_date, e);
+                    telemetry.capture_error("fetch_shows_for_date", &station_tags, &e);
                 }
             }
 
@@ -169,12 +238,12 @@ async fn main() -> Result<()> {
                 println!("  ... and {} more tracks", all_tracks.len() - 5);
             }
 
-            // Create/update Spotify playlist if requested
-            if let Some(ref mut spotify) = spotify_client {
-                match spotify.create_or_update_show_playlist(&show_group).await {
+            // Create/update the playlist on the selected provider if requested
+            if let Some(ref mut engine) = engine {
+                match engine.publish(&show_group).await {
                     Ok(playlist) => {
                         println!(
-                            "✅ Successfully created/updated Spotify playlist: {}",
+                            "✅ Successfully created/updated playlist: {}",
                             playlist.name
                         );
                         if let Some(url) = playlist.external_url {
@@ -183,7 +252,7 @@ async fn main() -> Result<()> {
                     }
                     Err(e) => {
                         eprintln!(
-                            "❌ Failed to create/update Spotify playlist for '{}': {}",
+                            "❌ Failed to create/update playlist for '{}': {}",
                             show_group.playlist_name(),
                             e
                         );
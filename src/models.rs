@@ -19,6 +19,23 @@ pub struct Track {
     pub time: Option<String>,
 }
 
+/// A `Track`'s dedup identity: the same lowercased `"artist - song"` string
+/// `all_tracks()` always keyed on, just wrapped so callers can't accidentally
+/// compare it against an unrelated `String`. This is scraped-`Track` dedup,
+/// before any provider match exists, so it can't key on a provider id.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TrackKey(String);
+
+impl Track {
+    pub fn key(&self) -> TrackKey {
+        TrackKey(format!(
+            "{} - {}",
+            self.artist.trim().to_lowercase(),
+            self.song.trim().to_lowercase()
+        ))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ShowGroup {
     pub station: String,
@@ -52,22 +69,21 @@ impl ShowGroup {
     
     pub fn all_tracks(&self) -> Vec<Track> {
         use std::collections::HashSet;
-        
+
         let mut all_tracks = Vec::new();
-        let mut seen_tracks = HashSet::new();
-        
+        let mut seen_tracks: HashSet<TrackKey> = HashSet::new();
+
         for episode in &self.episodes {
             for track in &episode.tracks {
-                // Create a unique key for deduplication (artist + song)
-                let track_key = format!("{} - {}", track.artist.trim().to_lowercase(), track.song.trim().to_lowercase());
-                
+                let track_key = track.key();
+
                 if !seen_tracks.contains(&track_key) {
                     seen_tracks.insert(track_key);
                     all_tracks.push(track.clone());
                 }
             }
         }
-        
+
         all_tracks
     }
     
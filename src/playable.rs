@@ -0,0 +1,44 @@
+use std::borrow::Cow;
+
+/// An identifier a `MusicProvider` assigns to something it matched a spin to
+/// (a Spotify track URI, a YouTube video id, ...). Stored as a `Cow` so a
+/// provider can hand back a borrowed id without forcing an allocation unless
+/// the caller needs to keep it past the match.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProviderId<'a>(Cow<'a, str>);
+
+impl<'a> ProviderId<'a> {
+    pub fn new(id: impl Into<Cow<'a, str>>) -> Self {
+        Self(id.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_owned(self) -> ProviderId<'static> {
+        ProviderId(Cow::Owned(self.0.into_owned()))
+    }
+}
+
+/// What a spin actually matched to on a provider. Community and talk-radio
+/// stations mix spoken-word segments into their spins, and a `MusicProvider`
+/// needs to say which one it found so a podcast episode doesn't silently end
+/// up where a song was expected.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Playable<'a> {
+    Track(ProviderId<'a>),
+    Episode(ProviderId<'a>),
+}
+
+impl<'a> Playable<'a> {
+    pub fn id(&self) -> &ProviderId<'a> {
+        match self {
+            Playable::Track(id) | Playable::Episode(id) => id,
+        }
+    }
+
+    pub fn is_track(&self) -> bool {
+        matches!(self, Playable::Track(_))
+    }
+}
@@ -0,0 +1,150 @@
+use anyhow::Result;
+use log::info;
+
+use crate::models::{ShowGroup, Track};
+use crate::playable::{Playable, ProviderId};
+use crate::resolver::{write_m3u_sidecar, InvidiousResolver, MediaKind, ResolvedMedia, TrackResolver};
+use crate::spotify::SpotifyClient;
+
+/// A human-presentable reference to a playlist a `MusicProvider` created or updated.
+pub struct PlaylistRef {
+    pub name: String,
+    pub external_url: Option<String>,
+}
+
+/// A backend a `ShowGroup` can be published to. `SpotifyClient` and
+/// `YouTubeProvider` both implement this so the orchestration loop in `main`
+/// doesn't need to know which one it's talking to.
+pub trait MusicProvider {
+    async fn search_track(&mut self, track: &Track) -> Result<Option<Playable<'static>>>;
+    async fn create_or_update_playlist(&mut self, show_group: &ShowGroup) -> Result<PlaylistRef>;
+}
+
+impl MusicProvider for SpotifyClient {
+    // A single ad-hoc lookup, for callers that want one match rather than a
+    // whole show's worth. `create_or_update_playlist` below doesn't call
+    // this: it resolves a show's tracks through its own concurrent,
+    // cache-aware batch path (`resolve_track_uris`), which this one-track-
+    // at-a-time, `&mut self` method can't be driven through without losing
+    // that concurrency.
+    async fn search_track(&mut self, track: &Track) -> Result<Option<Playable<'static>>> {
+        // Spotify's track search only ever returns songs, so a match here is
+        // always a `Track` (never an `Episode`).
+        Ok(SpotifyClient::search_track(self, track)
+            .await?
+            .map(|t| Playable::Track(ProviderId::new(t.uri))))
+    }
+
+    async fn create_or_update_playlist(&mut self, show_group: &ShowGroup) -> Result<PlaylistRef> {
+        let playlist = self.create_or_update_show_playlist(show_group).await?;
+        Ok(PlaylistRef {
+            name: playlist.name,
+            external_url: playlist.external_url,
+        })
+    }
+}
+
+/// Publishes playlists to YouTube via a configured Invidious instance. There's
+/// no YouTube playlist API wired up here, so the "playlist" is an M3U sidecar
+/// of resolved video URLs, the same format the Spotify fallback writes.
+pub struct YouTubeProvider {
+    resolver: InvidiousResolver,
+    cache_dir: String,
+}
+
+/// Replaces each `/` in a playlist name with `-` so it's safe to use as a file name.
+fn sanitize_playlist_filename(name: &str) -> String {
+    name.replace('/', "-")
+}
+
+impl YouTubeProvider {
+    pub fn new(instance_url: String) -> Result<Self> {
+        let cache_dir = "youtube_cache".to_string();
+        if !std::path::Path::new(&cache_dir).exists() {
+            std::fs::create_dir_all(&cache_dir)?;
+        }
+
+        Ok(Self {
+            resolver: InvidiousResolver::new(instance_url),
+            cache_dir,
+        })
+    }
+
+    fn playable_from(media: &ResolvedMedia) -> Playable<'static> {
+        let id = ProviderId::new(media.url.clone());
+        match media.kind {
+            MediaKind::Track => Playable::Track(id),
+            MediaKind::Episode => Playable::Episode(id),
+        }
+    }
+}
+
+impl MusicProvider for YouTubeProvider {
+    async fn search_track(&mut self, track: &Track) -> Result<Option<Playable<'static>>> {
+        Ok(self
+            .resolver
+            .resolve(&track.artist, &track.song)
+            .await
+            .map(|media| Self::playable_from(&media)))
+    }
+
+    async fn create_or_update_playlist(&mut self, show_group: &ShowGroup) -> Result<PlaylistRef> {
+        let playlist_name = show_group.playlist_name();
+        let tracks = show_group.all_tracks();
+        info!("Resolving {} tracks via Invidious for '{}'...", tracks.len(), playlist_name);
+
+        let mut resolved = Vec::new();
+        let mut skipped_episodes = 0;
+        for track in &tracks {
+            if let Some(media) = self.resolver.resolve(&track.artist, &track.song).await {
+                // A long Invidious match for a spin is more likely a talk
+                // segment or podcast upload than a song; don't drop it into
+                // the track playlist. Bucket through the same `Playable` a
+                // `MusicProvider::search_track` caller would see, rather than
+                // checking `media.kind` directly, so there's one bucketing
+                // rule instead of two.
+                if Self::playable_from(&media).is_track() {
+                    resolved.push(media);
+                } else {
+                    skipped_episodes += 1;
+                }
+            }
+        }
+        if skipped_episodes > 0 {
+            info!(
+                "Skipped {} likely spoken-word/podcast matches",
+                skipped_episodes
+            );
+        }
+
+        let path = format!(
+            "{}/{}.m3u",
+            self.cache_dir,
+            sanitize_playlist_filename(&playlist_name)
+        );
+        write_m3u_sidecar(&path, &resolved)?;
+        info!("Wrote YouTube playlist with {} tracks to {}", resolved.len(), path);
+
+        Ok(PlaylistRef {
+            name: playlist_name,
+            external_url: Some(path),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanitize_playlist_filename_replaces_slashes() {
+        assert_eq!(
+            sanitize_playlist_filename("KALX - Weekend Mix"),
+            "KALX - Weekend Mix"
+        );
+        assert_eq!(
+            sanitize_playlist_filename("KALX - AM/FM"),
+            "KALX - AM-FM"
+        );
+    }
+}
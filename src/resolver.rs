@@ -0,0 +1,103 @@
+use anyhow::Result;
+use reqwest::Client;
+use serde_json::Value;
+use std::path::Path;
+
+/// Videos longer than this are assumed to be talk/interview/podcast content
+/// rather than a song, since a single spin is never a 20-minute track.
+const EPISODE_LENGTH_THRESHOLD_SECS: u64 = 20 * 60;
+
+/// Whether a `TrackResolver` match looks like a song or a spoken-word
+/// segment. Community and talk-radio stations mix both into their spins, and
+/// an Invidious search for a spoken-word title can easily land on a podcast
+/// upload instead of a song.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaKind {
+    Track,
+    Episode,
+}
+
+/// A platform-specific match found by a `TrackResolver`, good enough to hand
+/// to a user as a clickable link or drop into a playlist file.
+#[derive(Debug, Clone)]
+pub struct ResolvedMedia {
+    pub title: String,
+    pub url: String,
+    pub kind: MediaKind,
+}
+
+/// Finds a platform-specific match for an (artist, title) pair. Intended as a
+/// fallback that runs after Spotify's own search comes up empty, so tracks
+/// that aren't on Spotify still end up somewhere playable.
+pub trait TrackResolver {
+    async fn resolve(&self, artist: &str, title: &str) -> Option<ResolvedMedia>;
+}
+
+/// Resolves tracks against a self-hosted [Invidious](https://docs.invidious.io/)
+/// instance, taking the most-viewed search result as the match. Most-viewed
+/// reliably lands on the official/popular upload rather than a cover or a
+/// low-quality rip.
+pub struct InvidiousResolver {
+    client: Client,
+    instance_url: String,
+}
+
+impl InvidiousResolver {
+    pub fn new(instance_url: String) -> Self {
+        Self {
+            client: Client::new(),
+            instance_url: instance_url.trim_end_matches('/').to_string(),
+        }
+    }
+}
+
+impl TrackResolver for InvidiousResolver {
+    async fn resolve(&self, artist: &str, title: &str) -> Option<ResolvedMedia> {
+        let query = format!("{} {}", artist, title);
+        let url = format!("{}/api/v1/search", self.instance_url);
+
+        let response = self
+            .client
+            .get(&url)
+            .query(&[("q", query.as_str()), ("type", "video")])
+            .send()
+            .await
+            .ok()?;
+
+        if !response.status().is_success() {
+            return None;
+        }
+
+        let results: Value = response.json().await.ok()?;
+        let best = results
+            .as_array()?
+            .iter()
+            .max_by_key(|video| video["viewCount"].as_u64().unwrap_or(0))?;
+
+        let video_id = best["videoId"].as_str()?;
+        let video_title = best["title"].as_str().unwrap_or(title).to_string();
+        let length_secs = best["lengthSeconds"].as_u64().unwrap_or(0);
+        let kind = if length_secs > EPISODE_LENGTH_THRESHOLD_SECS {
+            MediaKind::Episode
+        } else {
+            MediaKind::Track
+        };
+
+        Some(ResolvedMedia {
+            title: video_title,
+            url: format!("https://www.youtube.com/watch?v={}", video_id),
+            kind,
+        })
+    }
+}
+
+/// Writes `entries` out as an M3U playlist file: the sidecar for tracks
+/// Spotify couldn't match, or `YouTubeProvider`'s whole playlist.
+pub fn write_m3u_sidecar<P: AsRef<Path>>(path: P, entries: &[ResolvedMedia]) -> Result<()> {
+    let mut contents = String::from("#EXTM3U\n");
+    for entry in entries {
+        contents.push_str(&format!("#EXTINF:-1,{}\n{}\n", entry.title, entry.url));
+    }
+    std::fs::write(path, contents)?;
+    Ok(())
+}
@@ -7,6 +7,12 @@ use std::fs;
 use std::path::Path;
 
 use crate::models::{Show, Track};
+use crate::telemetry::TelemetrySink;
+
+/// Below this size a page is assumed to be an empty shell (Spinitron's "no
+/// spins yet" state, an error page, etc) rather than a real playlist that
+/// `parse_playlist_html` failed to read.
+const EMPTY_RESULT_HTML_LEN_THRESHOLD: usize = 500;
 
 pub struct SpinitronClient {
     client: Client,
@@ -107,7 +113,11 @@ pub async fn fetch_shows_for_date(station: &str, date: NaiveDate) -> Result<Vec<
     Ok(shows)
 }
 
-pub async fn fetch_playlist(url: &str) -> Result<Vec<Track>> {
+pub async fn fetch_playlist(
+    url: &str,
+    telemetry: &dyn TelemetrySink,
+    tags: &[(&str, &str)],
+) -> Result<Vec<Track>> {
     let client = SpinitronClient::new();
     // Extract show name from URL for better logging
     let show_name = url.split('/').last().unwrap_or("playlist");
@@ -115,7 +125,22 @@ pub async fn fetch_playlist(url: &str) -> Result<Vec<Track>> {
         .fetch_with_cache(url, &format!("playlist for {}", show_name))
         .await?;
 
-    parse_playlist_html(&html_content)
+    let tracks = parse_playlist_html(&html_content)?;
+
+    if tracks.is_empty() && html_content.trim().len() > EMPTY_RESULT_HTML_LEN_THRESHOLD {
+        let mut all_tags = tags.to_vec();
+        all_tags.push(("url", url));
+        telemetry.capture_error(
+            "fetch_playlist:zero_tracks_parsed",
+            &all_tags,
+            &anyhow::anyhow!(
+                "parse_playlist_html found 0 tracks in a {}-byte page; the markup may have changed",
+                html_content.len()
+            ),
+        );
+    }
+
+    Ok(tracks)
 }
 
 pub fn parse_playlist_html(html: &str) -> Result<Vec<Track>> {
@@ -1,13 +1,193 @@
 use anyhow::{anyhow, Result};
 use base64::{Engine as _, engine::general_purpose};
-use reqwest::Client;
+use futures::stream::{self, StreamExt};
+use log::{info, warn};
+use reqwest::{Client, RequestBuilder, Response, StatusCode};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
+use std::time::{Duration, Instant};
 
 use crate::models::{Track, ShowGroup};
+use crate::resolver::{write_m3u_sidecar, InvidiousResolver, TrackResolver};
+use crate::telemetry::TelemetrySink;
+
+/// Spotify base62 ids are always 22 characters.
+const SPOTIFY_ID_LEN: usize = 22;
+
+fn parse_spotify_id<'a>(value: &'a str, kind: &'static str) -> Result<Cow<'a, str>> {
+    let id = if let Some(rest) = value.strip_prefix("spotify:") {
+        let mut parts = rest.splitn(2, ':');
+        let found_kind = parts.next().unwrap_or("");
+        let id = parts
+            .next()
+            .ok_or_else(|| anyhow!("Malformed Spotify URI '{}': expected spotify:{}:<id>", value, kind))?;
+        if found_kind != kind {
+            return Err(anyhow!("Expected a '{}' URI but got '{}'", kind, value));
+        }
+        id
+    } else {
+        value
+    };
+
+    if id.len() != SPOTIFY_ID_LEN || !id.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(anyhow!(
+            "Invalid Spotify {} id '{}': expected {} base62 characters",
+            kind, id, SPOTIFY_ID_LEN
+        ));
+    }
+
+    Ok(Cow::Borrowed(id))
+}
+
+/// Builds the JSON request body for a playlist description update.
+fn description_update_body(description: &str) -> Result<String> {
+    Ok(serde_json::to_string(&serde_json::json!({ "description": description }))?)
+}
+
+/// Pulls the next page's URL out of a paginated Spotify API response, if any.
+fn next_page_url(json: &Value) -> Option<String> {
+    json["next"].as_str().map(|s| s.to_string())
+}
+
+/// Diffs a playlist's current tracks against the desired set, returning
+/// `(to_add, to_remove)` so `sync_playlist_tracks` can update just the
+/// difference instead of clearing and re-adding everything.
+fn diff_track_ids(
+    current: &[TrackId<'static>],
+    desired: &[TrackId<'static>],
+) -> (Vec<TrackId<'static>>, Vec<TrackId<'static>>) {
+    let desired_ids: std::collections::HashSet<&str> = desired.iter().map(|id| id.id()).collect();
+    let current_ids: std::collections::HashSet<&str> = current.iter().map(|id| id.id()).collect();
+
+    let to_remove = current
+        .iter()
+        .filter(|id| !desired_ids.contains(id.id()))
+        .cloned()
+        .collect();
+    let to_add = desired
+        .iter()
+        .filter(|id| !current_ids.contains(id.id()))
+        .cloned()
+        .collect();
+
+    (to_add, to_remove)
+}
+
+/// A validated Spotify track id/URI. Stores the bare base62 id in a `Cow` so
+/// parsing a borrowed `&str` (e.g. a JSON field we're about to discard) doesn't
+/// force an allocation unless the caller needs to keep it past that borrow.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TrackId<'a>(Cow<'a, str>);
+
+impl<'a> TrackId<'a> {
+    pub fn parse(value: &'a str) -> Result<Self> {
+        Ok(Self(parse_spotify_id(value, "track")?))
+    }
+
+    pub fn id(&self) -> &str {
+        &self.0
+    }
+
+    pub fn uri(&self) -> String {
+        format!("spotify:track:{}", self.0)
+    }
+
+    pub fn into_owned(self) -> TrackId<'static> {
+        TrackId(Cow::Owned(self.0.into_owned()))
+    }
+}
+
+impl<'a> TryFrom<&'a str> for TrackId<'a> {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &'a str) -> Result<Self> {
+        Self::parse(value)
+    }
+}
+
+/// A validated Spotify playlist id/URI, parsed the same way as `TrackId`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PlaylistId<'a>(Cow<'a, str>);
+
+impl<'a> PlaylistId<'a> {
+    pub fn parse(value: &'a str) -> Result<Self> {
+        Ok(Self(parse_spotify_id(value, "playlist")?))
+    }
+
+    pub fn id(&self) -> &str {
+        &self.0
+    }
+
+    pub fn uri(&self) -> String {
+        format!("spotify:playlist:{}", self.0)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for PlaylistId<'a> {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &'a str) -> Result<Self> {
+        Self::parse(value)
+    }
+}
+
+/// Summary of the work `SpotifyClient::sync_playlist_tracks` actually did.
+pub struct PlaylistSyncSummary {
+    pub added: usize,
+    pub removed: usize,
+}
+
+/// An access token shared across the concurrent search tasks spawned by
+/// `resolve_track_uris`, so a 401 discovered by one in-flight request
+/// refreshes it for the rest of the batch instead of each task silently
+/// falling through to a miss once the token expires mid-batch.
+#[derive(Clone)]
+struct SharedToken(std::sync::Arc<tokio::sync::Mutex<(String, Instant)>>);
+
+impl SharedToken {
+    fn new(access_token: String, expires_at: Instant) -> Self {
+        Self(std::sync::Arc::new(tokio::sync::Mutex::new((access_token, expires_at))))
+    }
+
+    async fn current(&self) -> String {
+        self.0.lock().await.0.clone()
+    }
+
+    async fn state(&self) -> (String, Instant) {
+        self.0.lock().await.clone()
+    }
+
+    /// Refreshes the token if it still matches `stale`; if another task already
+    /// refreshed it while we were waiting on the lock, just returns that instead.
+    async fn refresh_if_stale(&self, client: &Client, stale: &str) -> Result<String> {
+        let mut guard = self.0.lock().await;
+        if guard.0 == stale {
+            let client_id = std::env::var("SPOTIFY_CLIENT_ID").unwrap_or_default();
+            let client_secret = std::env::var("SPOTIFY_CLIENT_SECRET").unwrap_or_default();
+            let refresh_token = std::env::var("SPOTIFY_REFRESH_TOKEN").unwrap_or_default();
+            let (access_token, expires_in) =
+                SpotifyClient::get_access_token(client, &client_id, &client_secret, &refresh_token).await?;
+            *guard = (access_token, Instant::now() + Duration::from_secs(expires_in));
+        }
+        Ok(guard.0.clone())
+    }
+}
+
+/// Maximum number of retry attempts for a single request before giving up.
+const MAX_RETRIES: u32 = 5;
+/// Fallback sleep when a 429 response doesn't carry a `Retry-After` header.
+const DEFAULT_RETRY_AFTER_SECS: u64 = 5;
+/// Refresh the access token this far ahead of its real expiry to avoid racing it.
+const TOKEN_EXPIRY_SLACK_SECS: u64 = 60;
+/// How many track searches to have in flight at once during bulk resolution.
+const SEARCH_CONCURRENCY: usize = 5;
+/// Minimum similarity score (0.0-1.0) for a relaxed-search candidate to be
+/// accepted as a fuzzy match instead of recording a hard miss.
+const FUZZY_MATCH_THRESHOLD: f64 = 0.6;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SpotifyTrack {
@@ -37,9 +217,39 @@ pub struct SpotifyFolder {
     pub name: String,
 }
 
+/// Whether a cached track search matched the strict `track:`/`artist:` query
+/// directly, or only turned up a hit after falling back to relaxed free-text
+/// search plus similarity scoring. Kept so operators can audit fuzzy matches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MatchKind {
+    Exact,
+    Fuzzy,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedTrackMatch {
+    pub track: SpotifyTrack,
+    pub match_kind: MatchKind,
+}
+
+/// Result of one `run_search` request: either the parsed candidates (possibly
+/// empty, a genuine miss) or an unrecoverable failure that callers must not
+/// treat the same as a miss.
+enum SearchOutcome {
+    Results(Vec<SpotifyTrack>),
+    Failed,
+}
+
+/// What `search_track_remote` found for one track.
+enum SearchResult {
+    Found(CachedTrackMatch),
+    NotFound,
+    Error,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct TrackSearchCache {
-    tracks: HashMap<String, Option<SpotifyTrack>>,
+    tracks: HashMap<String, Option<CachedTrackMatch>>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -50,10 +260,13 @@ struct PlaylistCache {
 pub struct SpotifyClient {
     client: Client,
     access_token: String,
+    token_expires_at: Instant,
     user_id: String,
     track_cache: TrackSearchCache,
     playlist_cache: PlaylistCache,
     cache_dir: String,
+    fallback_resolver: Option<InvidiousResolver>,
+    telemetry: Box<dyn TelemetrySink>,
 }
 
 impl SpotifyClient {
@@ -74,11 +287,12 @@ impl SpotifyClient {
         }
 
         // Get access token
-        let access_token = Self::get_access_token(&client, &client_id, &client_secret, &refresh_token).await?;
-        
+        let (access_token, expires_in) = Self::get_access_token(&client, &client_id, &client_secret, &refresh_token).await?;
+        let token_expires_at = Instant::now() + Duration::from_secs(expires_in);
+
         // Get user ID and verify permissions
         let user_id = Self::get_user_id(&client, &access_token).await?;
-        println!("✅ Spotify client initialized for user: {}", user_id);
+        info!("✅ Spotify client initialized for user: {}", user_id);
         
         // Test if we can read user's playlists (to verify token permissions)
         let test_response = client
@@ -88,9 +302,9 @@ impl SpotifyClient {
             .await?;
         
         if test_response.status().is_success() {
-            println!("✅ Token has playlist permissions");
+            info!("✅ Token has playlist permissions");
         } else {
-            println!("⚠️  Token may not have sufficient permissions: {}", test_response.status());
+            warn!("⚠️  Token may not have sufficient permissions: {}", test_response.status());
         }
 
         // Load track cache only - we'll always refresh playlist cache from Spotify
@@ -99,19 +313,42 @@ impl SpotifyClient {
             playlists: std::collections::HashMap::new(),
         };
 
+        // Optional fallback for tracks Spotify's search can't find.
+        let fallback_resolver = std::env::var("INVIDIOUS_INSTANCE_URL")
+            .ok()
+            .map(InvidiousResolver::new);
+        if fallback_resolver.is_some() {
+            info!("✅ Invidious fallback enabled for unmatched tracks");
+        }
+
+        let telemetry = crate::telemetry::default_sink();
+
         Ok(Self {
             client,
             access_token,
+            token_expires_at,
             user_id,
             track_cache,
             playlist_cache,
             cache_dir,
+            fallback_resolver,
+            telemetry,
         })
     }
 
-    async fn get_access_token(client: &Client, client_id: &str, client_secret: &str, refresh_token: &str) -> Result<String> {
+    /// Reports `err` to the configured telemetry sink before handing it back
+    /// to the caller, so add/remove/refresh failures aren't silently lost once
+    /// they scroll off the log in a long unattended run.
+    fn report_error(&self, context: &str, err: anyhow::Error) -> anyhow::Error {
+        self.telemetry.capture_error(context, &[], &err);
+        err
+    }
+
+    /// Returns the new access token along with its `expires_in` lifetime (seconds),
+    /// so callers can track the deadline instead of waiting to hit a 401.
+    async fn get_access_token(client: &Client, client_id: &str, client_secret: &str, refresh_token: &str) -> Result<(String, u64)> {
         let auth_header = general_purpose::STANDARD.encode(format!("{}:{}", client_id, client_secret));
-        
+
         let params = [
             ("grant_type", "refresh_token"),
             ("refresh_token", refresh_token),
@@ -125,11 +362,14 @@ impl SpotifyClient {
             .await?;
 
         let json: Value = response.json().await?;
-        
-        json["access_token"]
+
+        let access_token = json["access_token"]
             .as_str()
             .map(|s| s.to_string())
-            .ok_or_else(|| anyhow!("Failed to get access token from Spotify"))
+            .ok_or_else(|| anyhow!("Failed to get access token from Spotify"))?;
+        let expires_in = json["expires_in"].as_u64().unwrap_or(3600);
+
+        Ok((access_token, expires_in))
     }
 
     async fn get_user_id(client: &Client, access_token: &str) -> Result<String> {
@@ -147,6 +387,88 @@ impl SpotifyClient {
             .ok_or_else(|| anyhow!("Failed to get user ID from Spotify"))
     }
 
+    async fn refresh_access_token(&mut self) -> Result<()> {
+        let client_id = std::env::var("SPOTIFY_CLIENT_ID").unwrap_or_default();
+        let client_secret = std::env::var("SPOTIFY_CLIENT_SECRET").unwrap_or_default();
+        let refresh_token = std::env::var("SPOTIFY_REFRESH_TOKEN").unwrap_or_default();
+        let (access_token, expires_in) = Self::get_access_token(&self.client, &client_id, &client_secret, &refresh_token).await?;
+        self.access_token = access_token;
+        self.token_expires_at = Instant::now() + Duration::from_secs(expires_in);
+        Ok(())
+    }
+
+    /// Refreshes the access token if it's within `TOKEN_EXPIRY_SLACK_SECS` of expiring,
+    /// so long batch runs don't have to eat a failed request to discover the token died.
+    async fn ensure_valid_token(&mut self) -> Result<()> {
+        let slack = Duration::from_secs(TOKEN_EXPIRY_SLACK_SECS);
+        if Instant::now() + slack >= self.token_expires_at {
+            warn!("  Access token nearing expiry, refreshing proactively...");
+            self.refresh_access_token().await?;
+        }
+        Ok(())
+    }
+
+    /// Sends a request built by `build`, routing it through Spotify's rate-limit and
+    /// auth-refresh handling so individual call sites don't have to.
+    ///
+    /// `build` is called with the current access token and must construct a fresh
+    /// `RequestBuilder` each time (rather than handing us one to clone), since it may be
+    /// called again after a token refresh or a 429/5xx backoff.
+    async fn send_with_retry<F>(&mut self, build: F) -> Result<Response>
+    where
+        F: Fn(&str) -> RequestBuilder,
+    {
+        self.ensure_valid_token().await?;
+
+        let mut attempts = 0;
+        let mut refreshed_for_401 = false;
+
+        loop {
+            let response = build(&self.access_token).send().await?;
+            let status = response.status();
+
+            if status.is_success() {
+                return Ok(response);
+            }
+
+            if status == StatusCode::UNAUTHORIZED && !refreshed_for_401 {
+                refreshed_for_401 = true;
+                warn!("  Access token rejected (401), refreshing...");
+                self.refresh_access_token().await?;
+                continue;
+            }
+
+            if status == StatusCode::TOO_MANY_REQUESTS {
+                attempts += 1;
+                if attempts > MAX_RETRIES {
+                    return Err(anyhow!("Exceeded {} retries due to rate limiting", MAX_RETRIES));
+                }
+                let wait_secs = response
+                    .headers()
+                    .get("Retry-After")
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|s| s.parse::<u64>().ok())
+                    .unwrap_or(DEFAULT_RETRY_AFTER_SECS);
+                warn!("  Rate limited (429), waiting {}s (attempt {}/{})", wait_secs, attempts, MAX_RETRIES);
+                tokio::time::sleep(Duration::from_secs(wait_secs)).await;
+                continue;
+            }
+
+            if status.is_server_error() {
+                attempts += 1;
+                if attempts > MAX_RETRIES {
+                    return Ok(response);
+                }
+                let backoff_secs = 1u64 << (attempts - 1);
+                warn!("  Spotify error {}, backing off {}s (attempt {}/{})", status, backoff_secs, attempts, MAX_RETRIES);
+                tokio::time::sleep(Duration::from_secs(backoff_secs)).await;
+                continue;
+            }
+
+            return Ok(response);
+        }
+    }
+
     fn load_track_cache(cache_dir: &str) -> TrackSearchCache {
         let cache_path = format!("{}/track_cache.json", cache_dir);
         if let Ok(content) = fs::read_to_string(&cache_path) {
@@ -168,58 +490,327 @@ impl SpotifyClient {
     }
 
 
+    fn spotify_track_from_json(track_data: &Value) -> SpotifyTrack {
+        SpotifyTrack {
+            id: track_data["id"].as_str().unwrap_or("").to_string(),
+            name: track_data["name"].as_str().unwrap_or("").to_string(),
+            artists: track_data["artists"]
+                .as_array()
+                .unwrap_or(&vec![])
+                .iter()
+                .map(|artist| SpotifyArtist {
+                    name: artist["name"].as_str().unwrap_or("").to_string(),
+                })
+                .collect(),
+            uri: track_data["uri"].as_str().unwrap_or("").to_string(),
+        }
+    }
+
     pub async fn search_track(&mut self, track: &Track) -> Result<Option<SpotifyTrack>> {
         let search_key = format!("{} - {}", track.artist, track.song);
-        
+
         // Check cache first
         if let Some(cached_result) = self.track_cache.tracks.get(&search_key) {
-            return Ok(cached_result.clone());
+            return Ok(cached_result.as_ref().map(|m| m.track.clone()));
         }
 
-        // Search Spotify
+        self.ensure_valid_token().await?;
+        let client = self.client.clone();
+        let token = SharedToken::new(self.access_token.clone(), self.token_expires_at);
+        let found = Self::search_track_remote(&client, &token, track).await;
+
+        let (access_token, token_expires_at) = token.state().await;
+        self.access_token = access_token;
+        self.token_expires_at = token_expires_at;
+
+        let result = match found {
+            SearchResult::Found(cached_match) => {
+                let spotify_track = cached_match.track.clone();
+                self.track_cache.tracks.insert(search_key, Some(cached_match));
+                Some(spotify_track)
+            }
+            SearchResult::NotFound => {
+                self.track_cache.tracks.insert(search_key, None);
+                None
+            }
+            SearchResult::Error => {
+                return Err(anyhow!("Spotify search failed for '{}'", search_key));
+            }
+        };
+        self.save_track_cache()?;
+
+        Ok(result)
+    }
+
+    fn strict_search_url(track: &Track) -> String {
         let query = format!("track:{} artist:{}", track.song, track.artist);
         let encoded_query = urlencoding::encode(&query);
-        
-        let url = format!(
+        format!(
             "https://api.spotify.com/v1/search?q={}&type=track&limit=1",
             encoded_query
+        )
+    }
+
+    fn relaxed_search_url(track: &Track) -> String {
+        let query = format!("{} {}", track.artist, track.song);
+        let encoded_query = urlencoding::encode(&query);
+        format!(
+            "https://api.spotify.com/v1/search?q={}&type=track&limit=10",
+            encoded_query
+        )
+    }
+
+    /// Normalizes a title/artist string for fuzzy comparison: drops a trailing
+    /// "feat./ft." credit and any parenthetical/bracketed content, strips
+    /// punctuation, lowercases, and collapses whitespace.
+    fn normalize_for_match(s: &str) -> String {
+        let lower = s.to_lowercase();
+        let without_feat = ["feat.", "feat ", "ft.", "ft "]
+            .iter()
+            .filter_map(|marker| {
+                lower
+                    .match_indices(marker)
+                    .map(|(idx, _)| idx)
+                    .find(|&idx| idx == 0 || lower[..idx].ends_with(char::is_whitespace))
+            })
+            .min()
+            .map(|idx| &lower[..idx])
+            .unwrap_or(&lower);
+
+        let mut without_parens = String::with_capacity(without_feat.len());
+        let mut depth = 0i32;
+        for c in without_feat.chars() {
+            match c {
+                '(' | '[' => depth += 1,
+                ')' | ']' => depth = (depth - 1).max(0),
+                _ if depth == 0 => without_parens.push(c),
+                _ => {}
+            }
+        }
+
+        without_parens
+            .chars()
+            .filter(|c| c.is_alphanumeric() || c.is_whitespace())
+            .collect::<String>()
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Dice's coefficient over character bigrams - a dependency-free string
+    /// similarity measure that's forgiving of small spelling/punctuation drift.
+    fn string_similarity(a: &str, b: &str) -> f64 {
+        fn bigrams(s: &str) -> std::collections::HashSet<(char, char)> {
+            let chars: Vec<char> = s.chars().collect();
+            chars.windows(2).map(|w| (w[0], w[1])).collect()
+        }
+
+        if a == b {
+            return 1.0;
+        }
+
+        let a_bigrams = bigrams(a);
+        let b_bigrams = bigrams(b);
+        if a_bigrams.is_empty() || b_bigrams.is_empty() {
+            return 0.0;
+        }
+
+        let shared = a_bigrams.intersection(&b_bigrams).count();
+        (2.0 * shared as f64) / (a_bigrams.len() + b_bigrams.len()) as f64
+    }
+
+    /// Scores a candidate against the Spinitron spin, averaging song-title and
+    /// primary-artist similarity after normalization.
+    fn candidate_score(track: &Track, candidate: &SpotifyTrack) -> f64 {
+        let song_score = Self::string_similarity(
+            &Self::normalize_for_match(&track.song),
+            &Self::normalize_for_match(&candidate.name),
+        );
+        let candidate_artist = candidate.artists.first().map(|a| a.name.as_str()).unwrap_or("");
+        let artist_score = Self::string_similarity(
+            &Self::normalize_for_match(&track.artist),
+            &Self::normalize_for_match(candidate_artist),
         );
+        (song_score + artist_score) / 2.0
+    }
 
-        let response = self.client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .send()
-            .await?;
+    /// What came back for one track search: a match, a genuine empty search
+    /// result, or an unrecoverable failure (401 after exhausting refreshes,
+    /// retry-exhaustion, network/parse error) that must not be cached as a
+    /// permanent "not found".
+    async fn search_track_remote(client: &Client, token: &SharedToken, track: &Track) -> SearchResult {
+        match Self::run_search(client, token, &Self::strict_search_url(track)).await {
+            SearchOutcome::Failed => return SearchResult::Error,
+            SearchOutcome::Results(results) => {
+                if let Some(spotify_track) = results.into_iter().next() {
+                    return SearchResult::Found(CachedTrackMatch {
+                        track: spotify_track,
+                        match_kind: MatchKind::Exact,
+                    });
+                }
+            }
+        }
 
-        let json: Value = response.json().await?;
-        
-        let spotify_track = if let Some(tracks) = json["tracks"]["items"].as_array() {
-            if let Some(track_data) = tracks.first() {
-                Some(SpotifyTrack {
-                    id: track_data["id"].as_str().unwrap_or("").to_string(),
-                    name: track_data["name"].as_str().unwrap_or("").to_string(),
-                    artists: track_data["artists"]
+        match Self::run_search(client, token, &Self::relaxed_search_url(track)).await {
+            SearchOutcome::Failed => SearchResult::Error,
+            SearchOutcome::Results(candidates) => candidates
+                .into_iter()
+                .map(|candidate| {
+                    let score = Self::candidate_score(track, &candidate);
+                    (score, candidate)
+                })
+                .filter(|(score, _)| *score >= FUZZY_MATCH_THRESHOLD)
+                .max_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+                .map(|(_, candidate)| {
+                    SearchResult::Found(CachedTrackMatch {
+                        track: candidate,
+                        match_kind: MatchKind::Fuzzy,
+                    })
+                })
+                .unwrap_or(SearchResult::NotFound),
+        }
+    }
+
+    /// Runs one Spotify search request, retrying 429/5xx locally and refreshing
+    /// `token` in place on a 401 so the rest of the concurrent batch picks up
+    /// the new token too, rather than each task discovering the 401 on its own.
+    async fn run_search(client: &Client, token: &SharedToken, url: &str) -> SearchOutcome {
+        let mut attempts = 0;
+        let mut access_token = token.current().await;
+
+        loop {
+            let response = match client
+                .get(url)
+                .header("Authorization", format!("Bearer {}", access_token))
+                .send()
+                .await
+            {
+                Ok(r) => r,
+                Err(_) => return SearchOutcome::Failed,
+            };
+            let status = response.status();
+
+            if status.is_success() {
+                let json: Value = match response.json().await {
+                    Ok(j) => j,
+                    Err(_) => return SearchOutcome::Failed,
+                };
+                return SearchOutcome::Results(
+                    json["tracks"]["items"]
                         .as_array()
-                        .unwrap_or(&vec![])
-                        .iter()
-                        .map(|artist| SpotifyArtist {
-                            name: artist["name"].as_str().unwrap_or("").to_string(),
-                        })
-                        .collect(),
-                    uri: track_data["uri"].as_str().unwrap_or("").to_string(),
+                        .map(|items| items.iter().map(Self::spotify_track_from_json).collect())
+                        .unwrap_or_default(),
+                );
+            }
+
+            if status == StatusCode::UNAUTHORIZED && attempts < MAX_RETRIES {
+                attempts += 1;
+                access_token = match token.refresh_if_stale(client, &access_token).await {
+                    Ok(refreshed) => refreshed,
+                    Err(_) => return SearchOutcome::Failed,
+                };
+                continue;
+            }
+
+            if status == StatusCode::TOO_MANY_REQUESTS && attempts < MAX_RETRIES {
+                attempts += 1;
+                let wait_secs = response
+                    .headers()
+                    .get("Retry-After")
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|s| s.parse::<u64>().ok())
+                    .unwrap_or(DEFAULT_RETRY_AFTER_SECS);
+                tokio::time::sleep(Duration::from_secs(wait_secs)).await;
+                continue;
+            }
+
+            if status.is_server_error() && attempts < MAX_RETRIES {
+                attempts += 1;
+                tokio::time::sleep(Duration::from_secs(1u64 << (attempts - 1))).await;
+                continue;
+            }
+
+            return SearchOutcome::Failed;
+        }
+    }
+
+    /// Resolves `tracks` to typed, validated track ids, searching cache misses
+    /// concurrently (bounded by `SEARCH_CONCURRENCY`) while preserving input
+    /// order. Saves the track cache once at the end rather than after every lookup.
+    async fn resolve_track_uris(&mut self, tracks: &[Track]) -> Result<(Vec<TrackId<'static>>, Vec<Track>, usize)> {
+        let mut resolved: Vec<Option<String>> = vec![None; tracks.len()];
+        let mut to_search = Vec::new();
+
+        for (i, track) in tracks.iter().enumerate() {
+            let search_key = format!("{} - {}", track.artist, track.song);
+            match self.track_cache.tracks.get(&search_key) {
+                Some(Some(cached)) => resolved[i] = Some(cached.track.uri.clone()),
+                Some(None) => {}
+                None => to_search.push((i, search_key, track.clone())),
+            }
+        }
+
+        if !to_search.is_empty() {
+            self.ensure_valid_token().await?;
+            let client = self.client.clone();
+            let token = SharedToken::new(self.access_token.clone(), self.token_expires_at);
+
+            let results = stream::iter(to_search)
+                .map(|(i, search_key, track)| {
+                    let client = client.clone();
+                    let token = token.clone();
+                    async move {
+                        let found = Self::search_track_remote(&client, &token, &track).await;
+                        (i, search_key, found)
+                    }
                 })
-            } else {
-                None
+                .buffer_unordered(SEARCH_CONCURRENCY)
+                .collect::<Vec<_>>()
+                .await;
+
+            for (i, search_key, found) in results {
+                match found {
+                    SearchResult::Found(cached_match) => {
+                        resolved[i] = Some(cached_match.track.uri.clone());
+                        self.track_cache.tracks.insert(search_key, Some(cached_match));
+                    }
+                    SearchResult::NotFound => {
+                        self.track_cache.tracks.insert(search_key, None);
+                    }
+                    SearchResult::Error => {
+                        warn!("  Search failed for '{}', leaving it uncached so it's retried next run", search_key);
+                    }
+                }
             }
-        } else {
-            None
-        };
 
-        // Cache the result
-        self.track_cache.tracks.insert(search_key, spotify_track.clone());
-        self.save_track_cache()?;
+            // A concurrent task may have refreshed the token on a 401; carry
+            // that back so the next batch/search doesn't start from a stale one.
+            let (access_token, token_expires_at) = token.state().await;
+            self.access_token = access_token;
+            self.token_expires_at = token_expires_at;
+            self.save_track_cache()?;
+        }
 
-        Ok(spotify_track)
+        let mut found_tracks = 0;
+        let mut track_uris = Vec::with_capacity(resolved.len());
+        let mut unmatched = Vec::new();
+        for (uri, track) in resolved.into_iter().zip(tracks.iter()) {
+            match uri {
+                Some(uri) => match TrackId::parse(&uri) {
+                    Ok(id) => {
+                        found_tracks += 1;
+                        track_uris.push(id.into_owned());
+                    }
+                    Err(e) => {
+                        warn!("  ✗ Spotify returned an invalid track id '{}': {}", uri, e);
+                        unmatched.push(track.clone());
+                    }
+                },
+                None => unmatched.push(track.clone()),
+            }
+        }
+
+        Ok((track_uris, unmatched, found_tracks))
     }
 
     pub async fn create_playlist(
@@ -235,7 +826,7 @@ impl SpotifyClient {
         let playlist_name = format!("{} - {}", sanitized_show_name, show_date);
         let spinitron_id_str = spinitron_id.to_string();
         
-        println!("Creating playlist: '{}' for Spinitron ID: {}", playlist_name, spinitron_id);
+        info!("Creating playlist: '{}' for Spinitron ID: {}", playlist_name, spinitron_id);
         
         // Validate playlist name length (Spotify has limits)
         if playlist_name.len() > 100 {
@@ -248,9 +839,9 @@ impl SpotifyClient {
         
         // Check if playlist already exists
         if let Some(existing_playlist) = self.playlist_cache.playlists.get(&spinitron_id_str) {
-            println!("Playlist already exists: {}", playlist_name);
+            info!("Playlist already exists: {}", playlist_name);
             if let Some(url) = &existing_playlist.external_url {
-                println!("  🔗 Share: {}", url);
+                info!("  🔗 Share: {}", url);
             }
             return Ok(existing_playlist.clone());
         }
@@ -268,34 +859,38 @@ impl SpotifyClient {
             "public": true
         });
 
-        println!("Playlist data being sent to Spotify:");
-        println!("  Name: '{}'", playlist_name);
-        println!("  Description: '{}'", description);
-        println!("  User ID: '{}'", self.user_id);
-        println!("  Payload: {}", serde_json::to_string_pretty(&playlist_data)?);
+        info!("Playlist data being sent to Spotify:");
+        info!("  Name: '{}'", playlist_name);
+        info!("  Description: '{}'", description);
+        info!("  User ID: '{}'", self.user_id);
+        info!("  Payload: {}", serde_json::to_string_pretty(&playlist_data)?);
 
-        println!("Sending playlist creation request to Spotify...");
+        info!("Sending playlist creation request to Spotify...");
         
         // Use the correct endpoint with user_id as per Spotify docs
         let url = format!("https://api.spotify.com/v1/users/{}/playlists", self.user_id);
-        println!("  URL: {}", url);
+        info!("  URL: {}", url);
         
-        let response = self.client
-            .post(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .header("Content-Type", "application/json")
-            .json(&playlist_data)
-            .send()
+        let client = self.client.clone();
+        let body = serde_json::to_string(&playlist_data)?;
+        let response = self
+            .send_with_retry(|token| {
+                client
+                    .post(&url)
+                    .header("Authorization", format!("Bearer {}", token))
+                    .header("Content-Type", "application/json")
+                    .body(body.clone())
+            })
             .await?;
 
         let status = response.status();
         let response_text = response.text().await?;
-        
+
         if !status.is_success() {
             return Err(anyhow!("Failed to create playlist. Status: {}, Response: {}", status, response_text));
         }
-        
-        println!("Spotify response: {}", response_text);
+
+        info!("Spotify response: {}", response_text);
         let playlist_json: Value = serde_json::from_str(&response_text)?;
         
         let playlist = SpotifyPlaylist {
@@ -306,72 +901,15 @@ impl SpotifyClient {
             external_url: playlist_json["external_urls"]["spotify"].as_str().map(|s| s.to_string()),
         };
 
-        // Add tracks to playlist
-        let mut track_uris = Vec::new();
-        let mut found_tracks = 0;
-        let mut not_found_tracks = 0;
-        
-        println!("Searching for {} tracks on Spotify...", tracks.len());
-        
-        for track in tracks {
-            match self.search_track(track).await {
-                Ok(Some(spotify_track)) => {
-                    track_uris.push(spotify_track.uri);
-                    found_tracks += 1;
-                    println!("  ✓ Found: {} - {}", track.artist, track.song);
-                }
-                Ok(None) => {
-                    not_found_tracks += 1;
-                    println!("  ✗ Not found: {} - {}", track.artist, track.song);
-                }
-                Err(e) => {
-                    not_found_tracks += 1;
-                    println!("  ✗ Error searching for {} - {}: {}", track.artist, track.song, e);
-                }
-            }
-            
-            if track_uris.len() % 10 == 0 && !track_uris.is_empty() {
-                // Add a small delay every 10 tracks to avoid rate limiting
-                tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
-            }
-        }
-        
-        println!("Track search complete: {} found, {} not found", found_tracks, not_found_tracks);
-
-        // Add tracks to playlist in batches of 100 (Spotify limit)
-        if !track_uris.is_empty() {
-            println!("Adding {} tracks to playlist...", track_uris.len());
-            
-            for (i, chunk) in track_uris.chunks(100).enumerate() {
-                let add_tracks_data = serde_json::json!({
-                    "uris": chunk
-                });
-
-                let response = self.client
-                    .post(&format!("https://api.spotify.com/v1/playlists/{}/tracks", playlist.id))
-                    .header("Authorization", format!("Bearer {}", self.access_token))
-                    .header("Content-Type", "application/json")
-                    .json(&add_tracks_data)
-                    .send()
-                    .await?;
-                
-                if !response.status().is_success() {
-                    let error_text = response.text().await?;
-                    return Err(anyhow!("Failed to add tracks batch {}: {}", i + 1, error_text));
-                }
-                
-                println!("  Added batch {} ({} tracks)", i + 1, chunk.len());
-            }
-        } else {
-            println!("No tracks found on Spotify to add to playlist");
-        }
+        // Resolve and add tracks to the new playlist
+        let added = self.add_tracks_to_playlist(&PlaylistId::parse(&playlist.id)?, tracks).await?;
 
         // Cache the playlist in memory
         self.playlist_cache.playlists.insert(spinitron_id_str, playlist.clone());
 
-        println!("Created playlist: {} with {} tracks", playlist_name, track_uris.len());
+        info!("Created playlist: {} with {} tracks", playlist_name, added);
         if let Some(url) = &playlist.external_url {
-            println!("  🔗 Share: {}", url);
+            info!("  🔗 Share: {}", url);
         }
         
         Ok(playlist)
@@ -382,12 +920,12 @@ impl SpotifyClient {
         let description = show_group.description();
         let latest_id = show_group.latest_spinitron_id();
         
-        println!("Processing show playlist: '{}'", playlist_name);
-        println!("  Episodes: {}", show_group.episodes.len());
-        println!("  Latest Spinitron ID: {}", latest_id);
+        info!("Processing show playlist: '{}'", playlist_name);
+        info!("  Episodes: {}", show_group.episodes.len());
+        info!("  Latest Spinitron ID: {}", latest_id);
         
         // Always refresh playlist cache from Spotify to avoid duplicates
-        println!("  Refreshing playlist cache from Spotify...");
+        info!("  Refreshing playlist cache from Spotify...");
         self.refresh_playlist_cache().await?;
         
         // Check if playlist already exists by name (more reliable than ID lookup)
@@ -396,43 +934,44 @@ impl SpotifyClient {
             .cloned();
         
         let playlist = if let Some(existing) = existing_playlist {
-            println!("Found existing playlist: {}", existing.name);
+            info!("Found existing playlist: {}", existing.name);
             
             // Parse existing latest ID from description
             let existing_latest_id = self.parse_latest_id_from_description(existing.description.as_ref().unwrap_or(&String::new()));
             
             if existing_latest_id >= latest_id {
-                println!("  Playlist is up to date (existing: {}, current: {})", existing_latest_id, latest_id);
+                info!("  Playlist is up to date (existing: {}, current: {})", existing_latest_id, latest_id);
                 return Ok(existing);
             }
             
-            println!("  Updating playlist with newer episodes (existing: {}, current: {})", existing_latest_id, latest_id);
+            info!("  Updating playlist with newer episodes (existing: {}, current: {})", existing_latest_id, latest_id);
             
-            // Replace all tracks with the latest 7-day collection
+            // Sync the playlist with the latest 7-day collection, touching only
+            // the tracks that actually changed instead of clearing and re-adding.
             let new_tracks = show_group.all_tracks();
-            println!("  Replacing playlist with {} tracks from last 7 days", new_tracks.len());
-            
-            // First, clear the existing playlist
-            self.clear_playlist_tracks(&existing.id).await?;
-            
-            // Then add all the new tracks
-            self.add_tracks_to_playlist(&existing.id, &new_tracks).await?;
-            
+            info!("  Syncing playlist with {} tracks from last 7 days", new_tracks.len());
+
+            let (desired, unmatched, found_tracks) = self.resolve_track_uris(&new_tracks).await?;
+            info!("Track search complete: {} found, {} not found", found_tracks, unmatched.len());
+            let existing_id = PlaylistId::parse(&existing.id)?;
+            self.sync_playlist_tracks(&existing_id, &desired).await?;
+            self.write_fallback_sidecar(&existing.id, &unmatched).await?;
+
             // Update the playlist description with new latest ID
             let updated_description = show_group.description();
-            self.update_playlist_description(&existing.id, &updated_description).await?;
+            self.update_playlist_description(&existing_id, &updated_description).await?;
             
             // Update in-memory cache
             self.playlist_cache.playlists.insert(latest_id.to_string(), existing.clone());
             
             existing
         } else {
-            println!("Creating new playlist");
+            info!("Creating new playlist");
             
             // Debug: Check playlist name and description lengths and content
-            println!("  Playlist name: '{}' (length: {})", playlist_name, playlist_name.len());
-            println!("  Description length: {}", description.len());
-            println!("  User ID: '{}'", self.user_id);
+            info!("  Playlist name: '{}' (length: {})", playlist_name, playlist_name.len());
+            info!("  Description length: {}", description.len());
+            info!("  User ID: '{}'", self.user_id);
             
             // Validate playlist name (Spotify requirements)
             if playlist_name.is_empty() {
@@ -446,7 +985,7 @@ impl SpotifyClient {
             }
             
             let url = format!("https://api.spotify.com/v1/users/{}/playlists", self.user_id);
-            println!("  Request URL: {}", url);
+            info!("  Request URL: {}", url);
             
             // Create new playlist
             let playlist_data = serde_json::json!({
@@ -455,82 +994,33 @@ impl SpotifyClient {
                 "public": true
             });
 
-            println!("  Playlist data: {}", serde_json::to_string_pretty(&playlist_data)?);
-            
-            // Test token validity and permissions
-            let test_response = self.client
-                .get("https://api.spotify.com/v1/me")
-                .header("Authorization", format!("Bearer {}", self.access_token))
-                .send()
-                .await?;
-            
-            if !test_response.status().is_success() {
-                return Err(anyhow!("Token appears invalid. Status: {}", test_response.status()));
-            }
-            
-            // Test playlist creation permissions by getting existing playlists
-            let playlist_test_response = self.client
-                .get("https://api.spotify.com/v1/me/playlists?limit=1")
-                .header("Authorization", format!("Bearer {}", self.access_token))
-                .send()
-                .await?;
-            
-            if !playlist_test_response.status().is_success() {
-                return Err(anyhow!("Token lacks playlist permissions. Status: {}", playlist_test_response.status()));
-            }
-            
+            info!("  Playlist data: {}", serde_json::to_string_pretty(&playlist_data)?);
+
             // Convert to JSON string manually to ensure proper encoding
             let json_payload = serde_json::to_string(&playlist_data)?;
-            println!("  JSON payload: {}", json_payload);
-            
-            let response = self.client
-                .post(&url)
-                .header("Authorization", format!("Bearer {}", self.access_token))
-                .header("Content-Type", "application/json")
-                .body(json_payload)
-                .send()
+            info!("  JSON payload: {}", json_payload);
+
+            let client = self.client.clone();
+            let response = self
+                .send_with_retry(|token| {
+                    client
+                        .post(&url)
+                        .header("Authorization", format!("Bearer {}", token))
+                        .header("Content-Type", "application/json")
+                        .body(json_payload.clone())
+                })
                 .await?;
 
             let status = response.status();
-            let mut response_text = response.text().await?;
-            
-            println!("  Response status: {}", status);
-            println!("  Response body: {}", response_text);
-            
+            let response_text = response.text().await?;
+
+            info!("  Response status: {}", status);
+            info!("  Response body: {}", response_text);
+
             if !status.is_success() {
-                // If it's an auth error, try refreshing the token
-                if status == 401 {
-                    println!("  Token may have expired, attempting to refresh...");
-                    self.access_token = Self::get_access_token(
-                        &self.client, 
-                        &std::env::var("SPOTIFY_CLIENT_ID").unwrap_or_default(),
-                        &std::env::var("SPOTIFY_CLIENT_SECRET").unwrap_or_default(),
-                        &std::env::var("SPOTIFY_REFRESH_TOKEN").unwrap_or_default()
-                    ).await?;
-                    
-                    // Retry the request with new token
-                    let json_payload = serde_json::to_string(&playlist_data)?;
-                    let retry_response = self.client
-                        .post(&url)
-                        .header("Authorization", format!("Bearer {}", self.access_token))
-                        .header("Content-Type", "application/json")
-                        .body(json_payload)
-                        .send()
-                        .await?;
-                    
-                    let retry_status = retry_response.status();
-                    let retry_response_text = retry_response.text().await?;
-                    
-                    if !retry_status.is_success() {
-                        return Err(anyhow!("Failed to create playlist after token refresh. Status: {}, Response: {}", retry_status, retry_response_text));
-                    }
-                    
-                    response_text = retry_response_text;
-                } else {
-                    return Err(anyhow!("Failed to create playlist. Status: {}, Response: {}", status, response_text));
-                }
+                return Err(anyhow!("Failed to create playlist. Status: {}, Response: {}", status, response_text));
             }
-            
+
             let playlist_json: Value = serde_json::from_str(&response_text)?;
             
             let playlist = SpotifyPlaylist {
@@ -543,7 +1033,7 @@ impl SpotifyClient {
 
             // Add tracks to the new playlist
             let all_tracks = show_group.all_tracks();
-            self.add_tracks_to_playlist(&playlist.id, &all_tracks).await?;
+            self.add_tracks_to_playlist(&PlaylistId::parse(&playlist.id)?, &all_tracks).await?;
             
             // Cache the playlist in memory
             self.playlist_cache.playlists.insert(latest_id.to_string(), playlist.clone());
@@ -551,21 +1041,23 @@ impl SpotifyClient {
             playlist
         };
         
-        println!("✅ Processed playlist: {} ({} total tracks)", playlist.name, show_group.all_tracks().len());
+        info!("✅ Processed playlist: {} ({} total tracks)", playlist.name, show_group.all_tracks().len());
         Ok(playlist)
     }
 
-    async fn update_playlist_description(&self, playlist_id: &str, description: &str) -> Result<()> {
-        let update_data = serde_json::json!({
-            "description": description
-        });
+    async fn update_playlist_description(&mut self, playlist_id: &PlaylistId<'_>, description: &str) -> Result<()> {
+        let body = description_update_body(description)?;
+        let client = self.client.clone();
+        let url = format!("https://api.spotify.com/v1/playlists/{}", playlist_id.id());
 
-        let response = self.client
-            .put(&format!("https://api.spotify.com/v1/playlists/{}", playlist_id))
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .header("Content-Type", "application/json")
-            .json(&update_data)
-            .send()
+        let response = self
+            .send_with_retry(|token| {
+                client
+                    .put(&url)
+                    .header("Authorization", format!("Bearer {}", token))
+                    .header("Content-Type", "application/json")
+                    .body(body.clone())
+            })
             .await?;
 
         if !response.status().is_success() {
@@ -576,70 +1068,122 @@ impl SpotifyClient {
         Ok(())
     }
 
-    async fn add_tracks_to_playlist(&mut self, playlist_id: &str, tracks: &[Track]) -> Result<()> {
-        let mut track_uris = Vec::new();
-        let mut found_tracks = 0;
-        let mut not_found_tracks = 0;
-        
-        println!("Searching for {} tracks on Spotify...", tracks.len());
-        
-        // For very large playlists, limit to first 500 tracks to avoid timeouts
-        let tracks_to_process = if tracks.len() > 500 {
-            println!("  Large playlist detected, limiting to first 500 tracks");
-            &tracks[..500]
-        } else {
-            tracks
+    /// Resolves `tracks` on Spotify and adds whatever is found to `playlist_id`,
+    /// returning the number of tracks actually added.
+    async fn add_tracks_to_playlist(&mut self, playlist_id: &PlaylistId<'_>, tracks: &[Track]) -> Result<usize> {
+        info!("Searching for {} tracks on Spotify (up to {} concurrent)...", tracks.len(), SEARCH_CONCURRENCY);
+
+        let (track_uris, unmatched, found_tracks) = self.resolve_track_uris(tracks).await?;
+
+        info!("Track search complete: {} found, {} not found", found_tracks, unmatched.len());
+
+        if !track_uris.is_empty() {
+            self.add_track_ids_to_playlist(playlist_id.id(), &track_uris).await?;
+        }
+
+        self.write_fallback_sidecar(playlist_id.id(), &unmatched).await?;
+
+        Ok(track_uris.len())
+    }
+
+    /// If an Invidious fallback is configured, resolves `unmatched` tracks against it
+    /// and writes the results as an M3U sidecar next to the cache, so tracks Spotify
+    /// couldn't find still end up somewhere playable. A no-op when unconfigured or
+    /// when nothing is unmatched.
+    async fn write_fallback_sidecar(&self, playlist_id: &str, unmatched: &[Track]) -> Result<()> {
+        let Some(resolver) = &self.fallback_resolver else {
+            return Ok(());
         };
-        
-        for (i, track) in tracks_to_process.iter().enumerate() {
-            if i % 50 == 0 {
-                println!("  Progress: {}/{} tracks processed", i, tracks_to_process.len());
-            }
-            
-            match self.search_track(track).await {
-                Ok(Some(spotify_track)) => {
-                    track_uris.push(spotify_track.uri);
-                    found_tracks += 1;
-                }
-                Ok(None) => {
-                    not_found_tracks += 1;
-                }
-                Err(_) => {
-                    not_found_tracks += 1;
-                }
+        if unmatched.is_empty() {
+            return Ok(());
+        }
+
+        info!("  Resolving {} unmatched tracks via Invidious fallback...", unmatched.len());
+
+        let mut resolved = Vec::new();
+        for track in unmatched {
+            if let Some(media) = resolver.resolve(&track.artist, &track.song).await {
+                resolved.push(media);
             }
-            
-            // Add delay every 10 tracks to avoid rate limiting
-            if i % 10 == 0 && i > 0 {
-                tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        }
+
+        if resolved.is_empty() {
+            return Ok(());
+        }
+
+        let path = format!("{}/{}_fallback.m3u", self.cache_dir, playlist_id);
+        write_m3u_sidecar(&path, &resolved)?;
+        info!("  Wrote {} fallback tracks to {}", resolved.len(), path);
+
+        Ok(())
+    }
+
+    /// Adds `ids` to `playlist_id` in batches of 100 (Spotify's limit).
+    async fn add_track_ids_to_playlist(&mut self, playlist_id: &str, ids: &[TrackId<'static>]) -> Result<()> {
+        info!("Adding {} tracks to playlist...", ids.len());
+
+        let client = self.client.clone();
+        let add_url = format!("https://api.spotify.com/v1/playlists/{}/tracks", playlist_id);
+        for (i, chunk) in ids.chunks(100).enumerate() {
+            let uris: Vec<String> = chunk.iter().map(|id| id.uri()).collect();
+            let body = serde_json::to_string(&serde_json::json!({ "uris": uris }))?;
+
+            let response = self
+                .send_with_retry(|token| {
+                    client
+                        .post(&add_url)
+                        .header("Authorization", format!("Bearer {}", token))
+                        .header("Content-Type", "application/json")
+                        .body(body.clone())
+                })
+                .await?;
+
+            if !response.status().is_success() {
+                let error_text = response.text().await?;
+                return Err(self.report_error(
+                    "add_track_ids_to_playlist",
+                    anyhow!("Failed to add tracks batch {}: {}", i + 1, error_text),
+                ));
             }
+
+            info!("  Added batch {} ({} tracks)", i + 1, chunk.len());
         }
-        
-        println!("Track search complete: {} found, {} not found", found_tracks, not_found_tracks);
 
-        if !track_uris.is_empty() {
-            println!("Adding {} tracks to playlist...", track_uris.len());
-            
-            for (i, chunk) in track_uris.chunks(100).enumerate() {
-                let add_tracks_data = serde_json::json!({
-                    "uris": chunk
-                });
-
-                let response = self.client
-                    .post(&format!("https://api.spotify.com/v1/playlists/{}/tracks", playlist_id))
-                    .header("Authorization", format!("Bearer {}", self.access_token))
-                    .header("Content-Type", "application/json")
-                    .json(&add_tracks_data)
-                    .send()
-                    .await?;
-                
-                if !response.status().is_success() {
-                    let error_text = response.text().await?;
-                    return Err(anyhow!("Failed to add tracks batch {}: {}", i + 1, error_text));
-                }
-                
-                println!("  Added batch {} ({} tracks)", i + 1, chunk.len());
+        Ok(())
+    }
+
+    /// Removes `ids` from `playlist_id` in batches of 100 (Spotify's limit).
+    async fn remove_track_ids_from_playlist(&mut self, playlist_id: &str, ids: &[TrackId<'static>]) -> Result<()> {
+        info!("    Removing {} existing tracks", ids.len());
+
+        let client = self.client.clone();
+        let remove_url = format!("https://api.spotify.com/v1/playlists/{}/tracks", playlist_id);
+
+        for (i, chunk) in ids.chunks(100).enumerate() {
+            let tracks_to_remove: Vec<serde_json::Value> = chunk.iter()
+                .map(|id| serde_json::json!({"uri": id.uri()}))
+                .collect();
+            let body = serde_json::to_string(&serde_json::json!({ "tracks": tracks_to_remove }))?;
+
+            let response = self
+                .send_with_retry(|token| {
+                    client
+                        .delete(&remove_url)
+                        .header("Authorization", format!("Bearer {}", token))
+                        .header("Content-Type", "application/json")
+                        .body(body.clone())
+                })
+                .await?;
+
+            if !response.status().is_success() {
+                let error_text = response.text().await?;
+                return Err(self.report_error(
+                    "remove_track_ids_from_playlist",
+                    anyhow!("Failed to remove tracks batch {}: {}", i + 1, error_text),
+                ));
             }
+
+            info!("    Removed batch {} ({} tracks)", i + 1, chunk.len());
         }
 
         Ok(())
@@ -655,155 +1199,145 @@ impl SpotifyClient {
         0
     }
     
-    async fn get_playlist_tracks(&self, playlist_id: &str) -> Result<Vec<String>> {
-        let mut all_track_uris = Vec::new();
-        let mut url = Some(format!("https://api.spotify.com/v1/playlists/{}/tracks?limit=100", playlist_id));
-        
-        while let Some(current_url) = url {
-            let response = self.client
-                .get(&current_url)
-                .header("Authorization", format!("Bearer {}", self.access_token))
-                .send()
-                .await?;
-            
-            if !response.status().is_success() {
-                let error_text = response.text().await?;
-                return Err(anyhow!("Failed to get playlist tracks: {}", error_text));
-            }
-            
-            let json: serde_json::Value = response.json().await?;
-            
-            if let Some(items) = json["items"].as_array() {
-                for item in items {
-                    if let Some(track) = item["track"].as_object() {
-                        if let Some(uri) = track["uri"].as_str() {
-                            all_track_uris.push(uri.to_string());
+    async fn get_playlist_tracks(&mut self, playlist_id: &PlaylistId<'_>) -> Result<Vec<TrackId<'static>>> {
+        let first_url = format!("https://api.spotify.com/v1/playlists/{}/tracks?limit=100", playlist_id.id());
+        self.fetch_all_pages(first_url, |page| {
+            page["items"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|item| {
+                    let uri = item["track"]["uri"].as_str()?;
+                    match TrackId::parse(uri) {
+                        Ok(id) => Some(id.into_owned()),
+                        Err(e) => {
+                            warn!("  ✗ Skipping playlist item with invalid track id '{}': {}", uri, e);
+                            None
                         }
                     }
-                }
-            }
-            
-            url = json["next"].as_str().map(|s| s.to_string());
-        }
-        
-        Ok(all_track_uris)
+                })
+                .collect()
+        })
+        .await
     }
 
-    async fn clear_playlist_tracks(&self, playlist_id: &str) -> Result<()> {
-        println!("    Clearing existing tracks from playlist...");
-        
-        // Get all current track URIs
-        let track_uris = self.get_playlist_tracks(playlist_id).await?;
-        
-        if track_uris.is_empty() {
-            println!("    Playlist is already empty");
-            return Ok(());
+    /// Reconciles `playlist_id`'s tracklist with `desired`, issuing only the
+    /// `DELETE`/`POST` batches needed to get there instead of clearing and
+    /// re-adding everything. Cheap on API calls and rate-limit budget for
+    /// playlists that barely changed week to week.
+    async fn sync_playlist_tracks(
+        &mut self,
+        playlist_id: &PlaylistId<'_>,
+        desired: &[TrackId<'static>],
+    ) -> Result<PlaylistSyncSummary> {
+        info!("    Syncing playlist tracks...");
+
+        let current = self.get_playlist_tracks(playlist_id).await?;
+        let (to_add, to_remove) = diff_track_ids(&current, desired);
+
+        if !to_remove.is_empty() {
+            self.remove_track_ids_from_playlist(playlist_id.id(), &to_remove).await?;
+        }
+        if !to_add.is_empty() {
+            self.add_track_ids_to_playlist(playlist_id.id(), &to_add).await?;
         }
-        
-        println!("    Removing {} existing tracks", track_uris.len());
-        
-        // Remove tracks in batches of 100 (Spotify limit)
-        for (i, chunk) in track_uris.chunks(100).enumerate() {
-            let tracks_to_remove: Vec<serde_json::Value> = chunk.iter()
-                .map(|uri| serde_json::json!({"uri": uri}))
-                .collect();
-            
-            let remove_tracks_data = serde_json::json!({
-                "tracks": tracks_to_remove
-            });
 
-            let response = self.client
-                .delete(&format!("https://api.spotify.com/v1/playlists/{}/tracks", playlist_id))
-                .header("Authorization", format!("Bearer {}", self.access_token))
-                .header("Content-Type", "application/json")
-                .json(&remove_tracks_data)
-                .send()
+        info!("    Sync complete: {} added, {} removed", to_add.len(), to_remove.len());
+
+        Ok(PlaylistSyncSummary {
+            added: to_add.len(),
+            removed: to_remove.len(),
+        })
+    }
+
+
+    /// Walks a Spotify paginated collection starting at `first_url`, following each
+    /// page's `next` link and routing every fetch through the retry layer. `extract`
+    /// turns one page's raw JSON into typed items, so callers aren't stuck with
+    /// `Value`s; this is the one place paging/error-checking/accumulation lives.
+    async fn fetch_all_pages<T, F>(&mut self, first_url: String, extract: F) -> Result<Vec<T>>
+    where
+        F: Fn(&Value) -> Vec<T>,
+    {
+        let mut items = Vec::new();
+        let mut url = Some(first_url);
+        let client = self.client.clone();
+
+        while let Some(current_url) = url {
+            let response = self
+                .send_with_retry(|token| {
+                    client
+                        .get(&current_url)
+                        .header("Authorization", format!("Bearer {}", token))
+                })
                 .await?;
-            
+
             if !response.status().is_success() {
                 let error_text = response.text().await?;
-                return Err(anyhow!("Failed to remove tracks batch {}: {}", i + 1, error_text));
+                return Err(anyhow!("Failed to fetch page: {}", error_text));
             }
-            
-            println!("    Removed batch {} ({} tracks)", i + 1, chunk.len());
+
+            let json: Value = response.json().await?;
+            items.extend(extract(&json));
+            url = next_page_url(&json);
         }
-        
-        Ok(())
-    }
 
+        Ok(items)
+    }
 
     pub async fn refresh_playlist_cache(&mut self) -> Result<()> {
-        println!("Refreshing playlist cache from Spotify...");
-        
-        let mut offset = 0;
-        let limit = 50;
+        info!("Refreshing playlist cache from Spotify...");
+
         let mut all_playlists = Vec::new();
+        let items: Vec<Value> = self
+            .fetch_all_pages(
+                "https://api.spotify.com/v1/me/playlists?limit=50".to_string(),
+                |page| page["items"].as_array().cloned().unwrap_or_default(),
+            )
+            .await
+            .map_err(|e| self.report_error("refresh_playlist_cache", e))?;
 
-        loop {
-            let url = format!(
-                "https://api.spotify.com/v1/me/playlists?limit={}&offset={}",
-                limit, offset
-            );
-
-            let response = self.client
-                .get(&url)
-                .header("Authorization", format!("Bearer {}", self.access_token))
-                .send()
-                .await?;
+        for item in &items {
+            let playlist_name = item["name"].as_str().unwrap_or("Unknown");
+            if let Some(description) = item["description"].as_str() {
+                // Look for either old format "Spinítron ID:" or new format "Latest ID:"
+                let has_generated = description.contains("Generated from Spinitron playlists");
+                let has_old_format = description.contains("Spinítron ID:");
+                let has_new_format = description.contains("Latest ID:");
+                let is_kalx = playlist_name.starts_with("KALX -");
 
-            let json: Value = response.json().await?;
-            
-            if let Some(items) = json["items"].as_array() {
-                for item in items {
-                    let playlist_name = item["name"].as_str().unwrap_or("Unknown");
-                    if let Some(description) = item["description"].as_str() {
-                        // Look for either old format "Spinítron ID:" or new format "Latest ID:"
-                        let has_generated = description.contains("Generated from Spinitron playlists");
-                        let has_old_format = description.contains("Spinítron ID:");
-                        let has_new_format = description.contains("Latest ID:");
-                        let is_kalx = playlist_name.starts_with("KALX -");
-                        
-                        // Be more flexible - match if it's a KALX playlist OR has our description
-                        let is_spinitron_playlist = has_generated || (is_kalx && (has_old_format || has_new_format));
-                        
-                        if is_spinitron_playlist {
-                            // Try to extract ID from either format, fallback to playlist name hash
-                            let spinitron_id = if let Some(id_str) = description.split("Latest ID: ").nth(1) {
-                                id_str.split_whitespace().next().unwrap_or("0").to_string()
-                            } else if let Some(spinitron_line) = description.lines().find(|line| line.contains("Spinítron ID:")) {
-                                if let Some(id_str) = spinitron_line.split(':').nth(1) {
-                                    let cleaned = id_str.trim().replace(['[', ']'], "");
-                                    cleaned.split(',').next().unwrap_or("0").trim().to_string()
-                                } else {
-                                    "0".to_string()
-                                }
-                            } else {
-                                // Fallback: use a hash of the playlist name for unique identification
-                                use std::collections::hash_map::DefaultHasher;
-                                use std::hash::{Hash, Hasher};
-                                let mut hasher = DefaultHasher::new();
-                                playlist_name.hash(&mut hasher);
-                                hasher.finish().to_string()
-                            };
-                            
-                            let playlist = SpotifyPlaylist {
-                                id: item["id"].as_str().unwrap_or("").to_string(),
-                                name: item["name"].as_str().unwrap_or("").to_string(),
-                                description: Some(description.to_string()),
-                                uri: item["uri"].as_str().unwrap_or("").to_string(),
-                                external_url: item["external_urls"]["spotify"].as_str().map(|s| s.to_string()),
-                            };
-                            all_playlists.push((spinitron_id, playlist));
+                // Be more flexible - match if it's a KALX playlist OR has our description
+                let is_spinitron_playlist = has_generated || (is_kalx && (has_old_format || has_new_format));
+
+                if is_spinitron_playlist {
+                    // Try to extract ID from either format, fallback to playlist name hash
+                    let spinitron_id = if let Some(id_str) = description.split("Latest ID: ").nth(1) {
+                        id_str.split_whitespace().next().unwrap_or("0").to_string()
+                    } else if let Some(spinitron_line) = description.lines().find(|line| line.contains("Spinítron ID:")) {
+                        if let Some(id_str) = spinitron_line.split(':').nth(1) {
+                            let cleaned = id_str.trim().replace(['[', ']'], "");
+                            cleaned.split(',').next().unwrap_or("0").trim().to_string()
+                        } else {
+                            "0".to_string()
                         }
-                    }
-                }
+                    } else {
+                        // Fallback: use a hash of the playlist name for unique identification
+                        use std::collections::hash_map::DefaultHasher;
+                        use std::hash::{Hash, Hasher};
+                        let mut hasher = DefaultHasher::new();
+                        playlist_name.hash(&mut hasher);
+                        hasher.finish().to_string()
+                    };
 
-                if items.len() < limit {
-                    break;
+                    let playlist = SpotifyPlaylist {
+                        id: item["id"].as_str().unwrap_or("").to_string(),
+                        name: item["name"].as_str().unwrap_or("").to_string(),
+                        description: Some(description.to_string()),
+                        uri: item["uri"].as_str().unwrap_or("").to_string(),
+                        external_url: item["external_urls"]["spotify"].as_str().map(|s| s.to_string()),
+                    };
+                    all_playlists.push((spinitron_id, playlist));
                 }
-                offset += limit;
-            } else {
-                break;
             }
         }
 
@@ -813,7 +1347,7 @@ impl SpotifyClient {
             self.playlist_cache.playlists.insert(spinitron_id, playlist);
         }
 
-        println!("Refreshed cache with {} playlists", self.playlist_cache.playlists.len());
+        info!("Refreshed cache with {} playlists", self.playlist_cache.playlists.len());
         
         Ok(())
     }
@@ -821,4 +1355,139 @@ impl SpotifyClient {
     pub fn get_cached_playlists(&self) -> &std::collections::HashMap<String, SpotifyPlaylist> {
         &self.playlist_cache.playlists
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_for_match_strips_a_feat_credit() {
+        assert_eq!(
+            SpotifyClient::normalize_for_match("Artist feat. Someone - Song"),
+            "artist"
+        );
+        assert_eq!(
+            SpotifyClient::normalize_for_match("Artist ft Someone"),
+            "artist"
+        );
+    }
+
+    #[test]
+    fn normalize_for_match_does_not_truncate_words_containing_ft() {
+        assert_eq!(SpotifyClient::normalize_for_match("Daft Punk"), "daft punk");
+        assert_eq!(SpotifyClient::normalize_for_match("Left Behind"), "left behind");
+        assert_eq!(SpotifyClient::normalize_for_match("Soft Focus"), "soft focus");
+    }
+
+    #[test]
+    fn normalize_for_match_strips_parens_and_punctuation() {
+        assert_eq!(
+            SpotifyClient::normalize_for_match("Song Title (Remastered 2011)!"),
+            "song title"
+        );
+    }
+
+    #[test]
+    fn string_similarity_is_one_for_identical_strings() {
+        assert_eq!(SpotifyClient::string_similarity("daft punk", "daft punk"), 1.0);
+    }
+
+    #[test]
+    fn string_similarity_is_zero_for_disjoint_strings() {
+        assert_eq!(SpotifyClient::string_similarity("abc", "xyz"), 0.0);
+    }
+
+    #[test]
+    fn candidate_score_averages_song_and_artist_similarity() {
+        let track = Track {
+            artist: "Daft Punk".to_string(),
+            song: "One More Time".to_string(),
+            album: String::new(),
+            label: None,
+            time: None,
+        };
+        let candidate = SpotifyTrack {
+            id: "id".to_string(),
+            name: "One More Time".to_string(),
+            artists: vec![SpotifyArtist {
+                name: "Daft Punk".to_string(),
+            }],
+            uri: "spotify:track:0000000000000000000000".to_string(),
+        };
+
+        assert_eq!(SpotifyClient::candidate_score(&track, &candidate), 1.0);
+    }
+
+    #[test]
+    fn track_id_parse_accepts_a_bare_id_and_a_uri() {
+        let bare = TrackId::parse("4uLU6hMCjMI75M1A2tKUQC").unwrap();
+        assert_eq!(bare.id(), "4uLU6hMCjMI75M1A2tKUQC");
+        assert_eq!(bare.uri(), "spotify:track:4uLU6hMCjMI75M1A2tKUQC");
+
+        let uri = TrackId::parse("spotify:track:4uLU6hMCjMI75M1A2tKUQC").unwrap();
+        assert_eq!(uri.id(), "4uLU6hMCjMI75M1A2tKUQC");
+    }
+
+    #[test]
+    fn track_id_parse_rejects_the_wrong_kind_and_bad_length() {
+        assert!(TrackId::parse("spotify:playlist:4uLU6hMCjMI75M1A2tKUQC").is_err());
+        assert!(TrackId::parse("too-short").is_err());
+    }
+
+    #[test]
+    fn playlist_id_parse_accepts_a_bare_id_and_a_uri() {
+        let bare = PlaylistId::parse("37i9dQZF1DXcBWIGoYBM5M").unwrap();
+        assert_eq!(bare.id(), "37i9dQZF1DXcBWIGoYBM5M");
+        assert_eq!(bare.uri(), "spotify:playlist:37i9dQZF1DXcBWIGoYBM5M");
+
+        assert!(PlaylistId::parse("spotify:track:37i9dQZF1DXcBWIGoYBM5M").is_err());
+    }
+
+    #[test]
+    fn description_update_body_encodes_as_json() {
+        let body = description_update_body("Latest ID: 42").unwrap();
+        assert_eq!(body, r#"{"description":"Latest ID: 42"}"#);
+    }
+
+    #[test]
+    fn next_page_url_reads_the_next_field() {
+        let json = serde_json::json!({ "next": "https://api.spotify.com/v1/me/playlists?offset=50" });
+        assert_eq!(
+            next_page_url(&json),
+            Some("https://api.spotify.com/v1/me/playlists?offset=50".to_string())
+        );
+    }
+
+    #[test]
+    fn next_page_url_is_none_on_the_last_page() {
+        let json = serde_json::json!({ "next": null });
+        assert_eq!(next_page_url(&json), None);
+    }
+
+    #[test]
+    fn diff_track_ids_finds_additions_and_removals() {
+        let a = TrackId::parse("1111111111111111111111").unwrap().into_owned();
+        let b = TrackId::parse("2222222222222222222222").unwrap().into_owned();
+        let c = TrackId::parse("3333333333333333333333").unwrap().into_owned();
+
+        let current = vec![a.clone(), b.clone()];
+        let desired = vec![b.clone(), c.clone()];
+
+        let (to_add, to_remove) = diff_track_ids(&current, &desired);
+
+        assert_eq!(to_add, vec![c]);
+        assert_eq!(to_remove, vec![a]);
+    }
+
+    #[test]
+    fn diff_track_ids_is_empty_when_unchanged() {
+        let a = TrackId::parse("1111111111111111111111").unwrap().into_owned();
+        let current = vec![a.clone()];
+        let desired = vec![a];
+
+        let (to_add, to_remove) = diff_track_ids(&current, &desired);
+
+        assert!(to_add.is_empty());
+        assert!(to_remove.is_empty());
+    }
+}
@@ -0,0 +1,63 @@
+/// Receives errors as they're about to propagate out of a fallible operation,
+/// so long unattended syncs don't lose context on failures once they scroll
+/// off the log. Implementations should not treat a reporting failure as fatal.
+pub trait TelemetrySink {
+    fn capture_error(&self, context: &str, tags: &[(&str, &str)], error: &anyhow::Error);
+}
+
+/// Default sink: telemetry is opt-in, so by default errors only go to the log.
+pub struct NoopTelemetrySink;
+
+impl TelemetrySink for NoopTelemetrySink {
+    fn capture_error(&self, _context: &str, _tags: &[(&str, &str)], _error: &anyhow::Error) {}
+}
+
+/// Reports errors to Sentry, tagged with the operation that produced them plus
+/// any caller-supplied tags (station, show, date, etc). Gated behind the
+/// `sentry` feature so the dependency is opt-in.
+#[cfg(feature = "sentry")]
+pub struct SentryTelemetrySink;
+
+#[cfg(feature = "sentry")]
+impl TelemetrySink for SentryTelemetrySink {
+    fn capture_error(&self, context: &str, tags: &[(&str, &str)], error: &anyhow::Error) {
+        sentry::with_scope(
+            |scope| {
+                scope.set_tag("context", context);
+                for (key, value) in tags {
+                    scope.set_tag(key, value);
+                }
+            },
+            || sentry::integrations::anyhow::capture_anyhow(error),
+        );
+    }
+}
+
+/// Builds the telemetry sink for this run: Sentry-backed when the `sentry`
+/// feature is enabled, a no-op otherwise.
+pub fn default_sink() -> Box<dyn TelemetrySink> {
+    #[cfg(feature = "sentry")]
+    {
+        Box::new(SentryTelemetrySink)
+    }
+    #[cfg(not(feature = "sentry"))]
+    {
+        Box::new(NoopTelemetrySink)
+    }
+}
+
+/// Initializes the Sentry client when the `sentry` feature is enabled and a
+/// `SENTRY_DSN` is present in the environment. The returned guard must be
+/// held for the life of the process: dropping it flushes and tears down the
+/// client, so without it `SentryTelemetrySink::capture_error` would have no
+/// Hub to report through.
+#[cfg(feature = "sentry")]
+pub fn init_sentry() -> Option<sentry::ClientInitGuard> {
+    let dsn = std::env::var("SENTRY_DSN").ok()?;
+    Some(sentry::init(dsn))
+}
+
+#[cfg(not(feature = "sentry"))]
+pub fn init_sentry() -> Option<()> {
+    None
+}